@@ -39,7 +39,183 @@ pub struct ArtifactMetadata {
     pub uncompressed_size: Option<u64>,
     /// The UUID of the artifact's Rust type
     pub type_id: AssetTypeId,
+    /// Type of encryption applied to this artifact, if any
+    #[serde(default)]
+    pub encryption: Option<EncryptionType>,
+    /// Identifier of the key used to encrypt this artifact, resolved out-of-band. `None`
+    /// when [Self::encryption] is `None`.
+    #[serde(default)]
+    pub key_id: Option<u64>,
+    /// Initialization vector used when encrypting this artifact. `None` when
+    /// [Self::encryption] is `None`.
+    #[serde(default)]
+    pub iv: Option<Vec<u8>>,
+}
+
+/// Scheme used to encrypt an artifact, modeled after common-encryption (CENC) style schemes:
+/// an algorithm id paired with a per-artifact initialization vector (see
+/// [ArtifactMetadata::iv]), with the key itself resolved out-of-band from
+/// [ArtifactMetadata::key_id].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq)]
+pub enum EncryptionType {
+    /// AES-128 in CTR mode.
+    Aes128Ctr,
+}
+
+/// Encodes and decodes artifact bytes for a [CompressionType] when they are written to or
+/// read from the artifact store.
+pub trait ArtifactCodec: Send + Sync {
+    /// Compresses `bytes`, the serialized artifact, for storage. [encode_artifact] runs this
+    /// before encryption, so `bytes` is never encrypted here.
+    fn encode(&self, bytes: &[u8]) -> Result<Vec<u8>>;
+    /// Reverses [Self::encode], returning the bytes as they were before compression.
+    fn decode(&self, bytes: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Use [inventory::submit!] to register an [ArtifactCodec] for a [CompressionType].
+pub struct ArtifactCodecRegistration {
+    pub compression: CompressionType,
+    pub instantiator: fn() -> Box<dyn ArtifactCodec>,
+}
+inventory::collect!(ArtifactCodecRegistration);
+
+/// Looks up the registered [ArtifactCodec] for `compression`, if any.
+pub fn find_artifact_codec(compression: CompressionType) -> Option<Box<dyn ArtifactCodec>> {
+    inventory::iter::<ArtifactCodecRegistration>
+        .into_iter()
+        .find(|r| r.compression == compression)
+        .map(|r| (r.instantiator)())
+}
+
+/// Encrypts and decrypts artifact bytes for an [EncryptionType] when they are written to or
+/// read from the artifact store. The key itself is resolved out-of-band from the
+/// `key_id` passed to each method; `iv` is the per-artifact initialization vector stored in
+/// [ArtifactMetadata::iv].
+pub trait ArtifactEncryption: Send + Sync {
+    /// Encrypts `bytes`, the (compressed) artifact, using the key identified by `key_id`.
+    fn encrypt(&self, bytes: &[u8], key_id: u64, iv: &[u8]) -> Result<Vec<u8>>;
+    /// Reverses [Self::encrypt].
+    fn decrypt(&self, bytes: &[u8], key_id: u64, iv: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Use [inventory::submit!] to register an [ArtifactEncryption] for an [EncryptionType].
+pub struct ArtifactEncryptionRegistration {
+    pub encryption: EncryptionType,
+    pub instantiator: fn() -> Box<dyn ArtifactEncryption>,
+}
+inventory::collect!(ArtifactEncryptionRegistration);
+
+/// Looks up the registered [ArtifactEncryption] for `encryption`, if any.
+pub fn find_artifact_encryption(
+    encryption: EncryptionType,
+) -> Option<Box<dyn ArtifactEncryption>> {
+    inventory::iter::<ArtifactEncryptionRegistration>
+        .into_iter()
+        .find(|r| r.encryption == encryption)
+        .map(|r| (r.instantiator)())
 }
+
+/// Builds an error for an artifact whose `compression`/`encryption` cannot be reversed: a
+/// codec or encryptor was requested but nothing is registered to satisfy it, or required
+/// encryption metadata (`key_id`/`iv`) is missing. This must be a hard error rather than a
+/// silent pass-through of `bytes`, since that would leave the artifact written or read in
+/// the clear while its metadata keeps claiming it is compressed/encrypted.
+fn unsatisfied_codec_error(message: impl Into<String>) -> crate::error::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message.into()).into()
+}
+
+/// Runs `bytes`, the serialized artifact, through the codec registered for
+/// `metadata.compression` (if any), then, if `metadata.encryption` is set, through the
+/// registered [ArtifactEncryption] keyed on `metadata.key_id`/`metadata.iv`. This is the
+/// single place compression and encryption are applied, so builders don't need to know
+/// which codec implements a given [CompressionType]/[EncryptionType]. Errors if a non-`None`
+/// `compression`/`encryption` has no matching codec registered, or if `encryption` is set
+/// without `key_id`/`iv` — never silently writes the artifact untransformed.
+pub fn encode_artifact(metadata: &ArtifactMetadata, bytes: &[u8]) -> Result<Vec<u8>> {
+    let bytes = if metadata.compression == CompressionType::None {
+        bytes.to_vec()
+    } else {
+        find_artifact_codec(metadata.compression)
+            .ok_or_else(|| {
+                unsatisfied_codec_error(format!(
+                    "no ArtifactCodec registered for compression {:?}",
+                    metadata.compression
+                ))
+            })?
+            .encode(bytes)?
+    };
+    match metadata.encryption {
+        None => Ok(bytes),
+        Some(encryption) => {
+            let key_id = metadata.key_id.ok_or_else(|| {
+                unsatisfied_codec_error(format!(
+                    "artifact is marked encryption: Some({:?}) but has no key_id",
+                    encryption
+                ))
+            })?;
+            let iv = metadata.iv.as_ref().ok_or_else(|| {
+                unsatisfied_codec_error(format!(
+                    "artifact is marked encryption: Some({:?}) but has no iv",
+                    encryption
+                ))
+            })?;
+            find_artifact_encryption(encryption)
+                .ok_or_else(|| {
+                    unsatisfied_codec_error(format!(
+                        "no ArtifactEncryption registered for {:?}",
+                        encryption
+                    ))
+                })?
+                .encrypt(&bytes, key_id, iv)
+        }
+    }
+}
+
+/// Reverses [encode_artifact], decoding bytes read from the artifact store back to the
+/// artifact's serialized representation. Called by the loader before deserializing an
+/// artifact: first reversing encryption (if `metadata.encryption` is set), then
+/// decompression. Errors the same way [encode_artifact] does rather than returning
+/// mismatched bytes silently.
+pub fn decode_artifact(metadata: &ArtifactMetadata, bytes: &[u8]) -> Result<Vec<u8>> {
+    let bytes = match metadata.encryption {
+        None => bytes.to_vec(),
+        Some(encryption) => {
+            let key_id = metadata.key_id.ok_or_else(|| {
+                unsatisfied_codec_error(format!(
+                    "artifact is marked encryption: Some({:?}) but has no key_id",
+                    encryption
+                ))
+            })?;
+            let iv = metadata.iv.as_ref().ok_or_else(|| {
+                unsatisfied_codec_error(format!(
+                    "artifact is marked encryption: Some({:?}) but has no iv",
+                    encryption
+                ))
+            })?;
+            find_artifact_encryption(encryption)
+                .ok_or_else(|| {
+                    unsatisfied_codec_error(format!(
+                        "no ArtifactEncryption registered for {:?}",
+                        encryption
+                    ))
+                })?
+                .decrypt(bytes, key_id, iv)?
+        }
+    };
+    if metadata.compression == CompressionType::None {
+        Ok(bytes)
+    } else {
+        find_artifact_codec(metadata.compression)
+            .ok_or_else(|| {
+                unsatisfied_codec_error(format!(
+                    "no ArtifactCodec registered for compression {:?}",
+                    metadata.compression
+                ))
+            })?
+            .decode(&bytes)
+    }
+}
+
 /// Version of the SourceMetadata struct.
 /// Used for forward compatibility to enable changing the .meta file format
 pub const SOURCEMETADATA_VERSION: u32 = 1;
@@ -64,6 +240,36 @@ pub struct SourceMetadata<Options: 'static, State: 'static> {
     pub assets: Vec<AssetMetadata>,
 }
 
+#[cfg(feature = "json")]
+impl<Options, State> SourceMetadata<Options, State> {
+    /// Flattens this metadata into a stable JSON object for debugging and editor
+    /// integrations that speak JSON rather than the RON `.meta` format. Includes the
+    /// version, import hash, importer type, per-asset ids and the size/compression info of
+    /// each asset's [ArtifactMetadata], but not the raw [Importer::Options]/[Importer::State]
+    /// (use [BoxedImporter::serialize_metadata_json] to include those for the erased types).
+    pub fn to_json_summary(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::json!({
+            "version": self.version,
+            "import_hash": self.import_hash,
+            "importer_version": self.importer_version,
+            "importer_type": self.importer_type,
+            "assets": self.assets.iter().map(|asset| {
+                serde_json::json!({
+                    "id": asset.id,
+                    "search_tags": asset.search_tags,
+                    "build_pipeline": asset.build_pipeline,
+                    "artifact": asset.artifact.as_ref().map(|artifact| serde_json::json!({
+                        "hash": artifact.hash,
+                        "compression": artifact.compression,
+                        "compressed_size": artifact.compressed_size,
+                        "uncompressed_size": artifact.uncompressed_size,
+                    })),
+                })
+            }).collect::<Vec<_>>(),
+        }))
+    }
+}
+
 /// Trait object wrapper for [Importer] implementations.
 /// Enables using Importers without knowing the concrete type.
 /// See [Importer] for documentation on fields.
@@ -83,6 +289,63 @@ pub trait BoxedImporter: TypeUuidDynamic + Send + Sync {
     ) -> Result<SourceMetadata<Box<dyn SerdeObj>, Box<dyn SerdeObj>>>;
     fn deserialize_options<'a>(&self, bytes: &'a [u8]) -> Result<Box<dyn SerdeObj>>;
     fn deserialize_state<'a>(&self, bytes: &'a [u8]) -> Result<Box<dyn SerdeObj>>;
+    /// Same as [Self::deserialize_metadata] but reads the JSON export produced by
+    /// [Self::serialize_metadata_json] instead of the RON `.meta` format.
+    #[cfg(feature = "json")]
+    fn deserialize_metadata_json<'a>(
+        &self,
+        json: &'a str,
+    ) -> Result<SourceMetadata<Box<dyn SerdeObj>, Box<dyn SerdeObj>>>;
+    /// Serializes `metadata` to a JSON object, downcasting the erased options/state back to
+    /// their concrete [Importer::Options]/[Importer::State] types so they serialize as more
+    /// than an opaque blob. Intended for debugging and editor integrations that speak JSON.
+    #[cfg(feature = "json")]
+    fn serialize_metadata_json(
+        &self,
+        metadata: &SourceMetadata<Box<dyn SerdeObj>, Box<dyn SerdeObj>>,
+    ) -> Result<serde_json::Value>;
+    /// Extracts search tags embedded in the source's own container metadata, e.g. an MP4
+    /// `udta` atom or Vorbis comments carrying title/artist/year or cover-art presence.
+    /// Tags returned here are merged into every [AssetMetadata::search_tags] produced when
+    /// importing `value`. The blanket impl for [Importer] types forwards to
+    /// [ImporterSearchTags], whose default returns no tags.
+    fn extract_search_tags(&self, value: &ImporterValue) -> Vec<(String, Option<String>)>;
+    /// Streaming variant of [Self::import_boxed] for large, multi-asset sources (e.g. a
+    /// video/audio container that decomposes into many tracks or fragments) where
+    /// materializing every produced asset in memory at once would not scale. `sink` is
+    /// invoked once per produced asset as soon as it is available, so it can be flushed to
+    /// the metadata DB / artifact store incrementally, keeping peak memory bounded.
+    ///
+    /// The default implementation drives [Self::import_boxed] and replays its assets
+    /// through `sink`, so existing importers keep working unchanged. It calls `sink` only
+    /// after the whole [ImporterValue] has been produced, so it does not bound peak memory.
+    /// The blanket impl for [Importer] types keeps this default; an [Importer] cannot
+    /// replace it with its own streaming implementation.
+    fn import_boxed_streaming(
+        &self,
+        source: &mut dyn Read,
+        options: Box<dyn SerdeObj>,
+        state: Box<dyn SerdeObj>,
+        sink: &mut dyn FnMut(AssetMetadata, Box<dyn SerdeObj>) -> Result<()>,
+    ) -> Result<BoxedImporterStreamResult> {
+        let BoxedImporterValue {
+            value,
+            options,
+            state,
+        } = self.import_boxed(source, options, state)?;
+        for asset in value.assets {
+            sink(
+                AssetMetadata {
+                    id: asset.id,
+                    search_tags: asset.search_tags,
+                    build_pipeline: asset.build_pipeline,
+                    artifact: None,
+                },
+                asset.asset_data,
+            )?;
+        }
+        Ok(BoxedImporterStreamResult { options, state })
+    }
 }
 
 impl std::fmt::Debug for dyn BoxedImporter {
@@ -99,6 +362,30 @@ pub struct BoxedImporterValue {
     pub state: Box<dyn SerdeObj>,
 }
 
+/// Result of [BoxedImporter::import_boxed_streaming]. Unlike [BoxedImporterValue], it has no
+/// `value` field: every produced asset has already been flushed through the sink by the time
+/// this is returned, so only the final options/state remain to be persisted.
+pub struct BoxedImporterStreamResult {
+    pub options: Box<dyn SerdeObj>,
+    pub state: Box<dyn SerdeObj>,
+}
+
+/// Hook for an [Importer] to surface embedded container metadata (title, artist, year,
+/// cover-art presence, etc.) as search tags. This lives on [Importer] rather than directly on
+/// [BoxedImporter]: every [BoxedImporter] comes from the blanket `impl<S, O, T> BoxedImporter
+/// for T` below, so a default method placed there can never be overridden by a concrete
+/// importer. This trait is itself implemented for every [Importer] by the blanket impl
+/// below, which keeps the default, so it returns no tags for every importer; a separate
+/// impl for a concrete importer type conflicts with that blanket impl.
+pub trait ImporterSearchTags: Importer {
+    /// See [BoxedImporter::extract_search_tags]. Defaults to no tags.
+    fn extract_search_tags(&self, value: &ImporterValue) -> Vec<(String, Option<String>)> {
+        let _ = value;
+        Vec::new()
+    }
+}
+impl<T: Importer> ImporterSearchTags for T {}
+
 impl<S, O, T> BoxedImporter for T
 where
     O: SerdeObj + Serialize + Default + Send + Sync + Clone + for<'a> Deserialize<'a>,
@@ -123,13 +410,22 @@ where
         } else {
             panic!("Failed to downcast Importer::Options");
         };
-        let result = self.import(source, o.clone(), &mut s)?;
+        let mut result = self.import(source, o.clone(), &mut s)?;
+        let tags = self.extract_search_tags(&result);
+        if !tags.is_empty() {
+            for asset in &mut result.assets {
+                asset.search_tags.extend(tags.iter().cloned());
+            }
+        }
         Ok(BoxedImporterValue {
             value: result,
             options: Box::new(o),
             state: s,
         })
     }
+    fn extract_search_tags(&self, value: &ImporterValue) -> Vec<(String, Option<String>)> {
+        ImporterSearchTags::extract_search_tags(self, value)
+    }
     fn default_options(&self) -> Box<dyn SerdeObj> {
         Box::new(O::default())
     }
@@ -160,20 +456,151 @@ where
     fn deserialize_state<'a>(&self, bytes: &'a [u8]) -> Result<Box<dyn SerdeObj>> {
         Ok(Box::new(bincode::deserialize::<S>(&bytes)?))
     }
+    #[cfg(feature = "json")]
+    fn deserialize_metadata_json<'a>(
+        &self,
+        json: &'a str,
+    ) -> Result<SourceMetadata<Box<dyn SerdeObj>, Box<dyn SerdeObj>>> {
+        let metadata: SourceMetadata<O, S> = serde_json::from_str(json)?;
+        Ok(SourceMetadata {
+            version: metadata.version,
+            import_hash: metadata.import_hash,
+            importer_version: metadata.importer_version,
+            importer_type: metadata.importer_type,
+            importer_options: Box::new(metadata.importer_options),
+            importer_state: Box::new(metadata.importer_state),
+            assets: metadata.assets.clone(),
+        })
+    }
+    #[cfg(feature = "json")]
+    fn serialize_metadata_json(
+        &self,
+        metadata: &SourceMetadata<Box<dyn SerdeObj>, Box<dyn SerdeObj>>,
+    ) -> Result<serde_json::Value> {
+        let options = metadata
+            .importer_options
+            .downcast_ref::<O>()
+            .expect("Failed to downcast Importer::Options");
+        let state = metadata
+            .importer_state
+            .downcast_ref::<S>()
+            .expect("Failed to downcast Importer::State");
+        Ok(serde_json::json!({
+            "version": metadata.version,
+            "import_hash": metadata.import_hash,
+            "importer_version": metadata.importer_version,
+            "importer_type": metadata.importer_type,
+            "importer_options": options,
+            "importer_state": state,
+            "assets": metadata.assets,
+        }))
+    }
 }
 
-/// Use [inventory::submit!] to register an importer to use for a file extension.
+/// Use [inventory::submit!] to register an importer to use for a set of file extensions.
 #[derive(Debug)]
 pub struct SourceFileImporter {
-    pub extension: &'static str,
+    /// Human-readable name for this importer, e.g. `"JPEG"`. Used by asset tooling to
+    /// present a friendly, extension-independent list of available importers.
+    pub name: &'static str,
+    /// Extensions this importer is registered for, without a leading dot, e.g. `&["jpg",
+    /// "jpeg"]`. An importer that should be offered for several equivalent extensions
+    /// registers all of them here instead of submitting once per extension.
+    pub extensions: &'static [&'static str],
     pub instantiator: fn() -> Box<dyn BoxedImporter>,
+    /// Optional content sniffer used to recognize a source file independent of its
+    /// extension. Given the first [DETECT_HEADER_LEN] bytes of the source (or fewer, if
+    /// the source is shorter), returns whether this importer can handle it.
+    pub detect: Option<fn(&[u8]) -> bool>,
 }
 inventory::collect!(SourceFileImporter);
 
-/// Get the registered importers and their associated extension.
+/// Get the registered importers along with their name and associated extensions.
 pub fn get_source_importers(
-) -> impl Iterator<Item = (&'static str, Box<dyn BoxedImporter + 'static>)> {
+) -> impl Iterator<Item = (&'static str, Vec<&'static str>, Box<dyn BoxedImporter + 'static>)> {
+    inventory::iter::<SourceFileImporter>.into_iter().map(|s| {
+        (
+            s.name,
+            s.extensions
+                .iter()
+                .map(|ext| ext.trim_start_matches('.'))
+                .collect(),
+            (s.instantiator)(),
+        )
+    })
+}
+
+/// Look up a registered importer by its [SourceFileImporter::name], ignoring extension.
+/// Lets asset tooling offer a named list of importers and override the extension-based
+/// choice when a user explicitly picks one.
+pub fn find_importer_by_name(name: &str) -> Option<Box<dyn BoxedImporter>> {
     inventory::iter::<SourceFileImporter>
         .into_iter()
-        .map(|s| (s.extension.trim_start_matches("."), (s.instantiator)()))
+        .find(|s| s.name == name)
+        .map(|s| (s.instantiator)())
+}
+
+/// Number of header bytes buffered when content-sniffing a source file to choose an importer.
+pub const DETECT_HEADER_LEN: usize = 16;
+
+/// A [Read] adapter that replays a buffered header before continuing to read from the
+/// wrapped reader. Used so the bytes consumed while sniffing a source file's header are
+/// not lost to the [Importer] that ends up importing it.
+struct HeaderReplay<'a> {
+    header: std::io::Cursor<Vec<u8>>,
+    rest: &'a mut dyn Read,
+}
+
+impl<'a> Read for HeaderReplay<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.header.read(buf)?;
+        if n > 0 {
+            return Ok(n);
+        }
+        self.rest.read(buf)
+    }
+}
+
+/// Detects which registered [SourceFileImporter] should import a source file.
+///
+/// Buffers up to [DETECT_HEADER_LEN] bytes from `source` and checks them against each
+/// importer's [SourceFileImporter::detect] content sniffer, falling back to matching `ext`
+/// against registered extensions if no sniffer recognizes the header. Returns the chosen
+/// importer along with a reader that replays the buffered header followed by the rest of
+/// `source`, so the importer still sees the full stream.
+pub fn detect_importer<'a>(
+    ext: &str,
+    source: &'a mut dyn Read,
+) -> Result<Option<(Box<dyn BoxedImporter>, impl Read + 'a)>> {
+    let ext = ext.trim_start_matches('.');
+    let mut header = vec![0u8; DETECT_HEADER_LEN];
+    let mut read = 0;
+    while read < header.len() {
+        let n = source.read(&mut header[read..])?;
+        if n == 0 {
+            break;
+        }
+        read += n;
+    }
+    header.truncate(read);
+
+    let importer = inventory::iter::<SourceFileImporter>
+        .into_iter()
+        .find(|s| s.detect.map_or(false, |detect| detect(&header)))
+        .or_else(|| {
+            inventory::iter::<SourceFileImporter>
+                .into_iter()
+                .find(|s| s.extensions.iter().any(|e| e.trim_start_matches('.') == ext))
+        })
+        .map(|s| (s.instantiator)());
+
+    Ok(importer.map(|importer| {
+        (
+            importer,
+            HeaderReplay {
+                header: std::io::Cursor::new(header),
+                rest: source,
+            },
+        )
+    }))
 }